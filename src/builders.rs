@@ -0,0 +1,175 @@
+use super::enums::ImageKind;
+use super::screenshot::{Image, MediaUrl, Screenshot, Video, VideoCodec, VideoContainer};
+use super::types::TranslatableString;
+use url::Url;
+
+impl From<Url> for MediaUrl {
+    fn from(url: Url) -> Self {
+        MediaUrl::Absolute(url)
+    }
+}
+
+impl From<&str> for VideoCodec {
+    fn from(value: &str) -> Self {
+        match value {
+            "av1" => VideoCodec::AV1,
+            "vp9" => VideoCodec::VP9,
+            other => VideoCodec::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl From<&str> for VideoContainer {
+    fn from(value: &str) -> Self {
+        match value {
+            "webm" => VideoContainer::WebM,
+            "matroska" => VideoContainer::Matroska,
+            other => VideoContainer::Unknown(other.to_string()),
+        }
+    }
+}
+
+pub struct ImageBuilder {
+    url: MediaUrl,
+    kind: ImageKind,
+    width: Option<u32>,
+    height: Option<u32>,
+}
+
+impl ImageBuilder {
+    pub fn new(url: impl Into<MediaUrl>) -> Self {
+        Self {
+            url: url.into(),
+            kind: ImageKind::Source,
+            width: None,
+            height: None,
+        }
+    }
+
+    pub fn kind(mut self, kind: ImageKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn build(self) -> Image {
+        Image {
+            kind: self.kind,
+            width: self.width,
+            height: self.height,
+            url: self.url,
+        }
+    }
+}
+
+pub struct VideoBuilder {
+    url: MediaUrl,
+    width: Option<u32>,
+    height: Option<u32>,
+    codec: Option<VideoCodec>,
+    container: Option<VideoContainer>,
+}
+
+impl VideoBuilder {
+    pub fn new(url: impl Into<MediaUrl>) -> Self {
+        Self {
+            url: url.into(),
+            width: None,
+            height: None,
+            codec: None,
+            container: None,
+        }
+    }
+
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn codec(mut self, codec: impl Into<VideoCodec>) -> Self {
+        self.codec = Some(codec.into());
+        self
+    }
+
+    pub fn container(mut self, container: impl Into<VideoContainer>) -> Self {
+        self.container = Some(container.into());
+        self
+    }
+
+    pub fn build(self) -> Video {
+        Video {
+            width: self.width,
+            height: self.height,
+            codec: self.codec,
+            container: self.container,
+            url: self.url,
+        }
+    }
+}
+
+pub struct ScreenshotBuilder {
+    is_default: bool,
+    caption: Option<TranslatableString>,
+    images: Vec<Image>,
+    videos: Vec<Video>,
+}
+
+impl ScreenshotBuilder {
+    pub fn new() -> Self {
+        Self {
+            is_default: true,
+            caption: None,
+            images: vec![],
+            videos: vec![],
+        }
+    }
+
+    pub fn set_default(mut self, is_default: bool) -> Self {
+        self.is_default = is_default;
+        self
+    }
+
+    pub fn caption(mut self, caption: TranslatableString) -> Self {
+        self.caption = Some(caption);
+        self
+    }
+
+    pub fn image(mut self, image: Image) -> Self {
+        self.images.push(image);
+        self
+    }
+
+    pub fn video(mut self, video: Video) -> Self {
+        self.videos.push(video);
+        self
+    }
+
+    pub fn build(self) -> Screenshot {
+        Screenshot {
+            is_default: self.is_default,
+            caption: self.caption,
+            images: self.images,
+            videos: self.videos,
+        }
+    }
+}
+
+impl Default for ScreenshotBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,7 @@
 use super::AppId;
 use super::Component;
+use super::Screenshot;
+use crate::screenshot::ValidationWarning;
 use anyhow::Result;
 #[cfg(feature = "gzip")]
 use flate2::read::GzDecoder;
@@ -10,12 +12,16 @@ use std::fs::File;
 #[cfg(feature = "gzip")]
 use std::io::prelude::*;
 use std::path::PathBuf;
+use url::Url;
 
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Collection {
     pub version: String,
     #[serde(default)]
     pub origin: Option<String>,
+    /// Base URL relative screenshot/video hrefs resolve against, via `resolve_media_urls`.
+    #[serde(default)]
+    pub media_baseurl: Option<Url>,
     #[serde(rename = "component", default)]
     pub components: Vec<Component>,
     // TODO: architecture
@@ -47,6 +53,45 @@ impl Collection {
             .filter(|c| c.id.0 == id.0)
             .collect::<Vec<&Component>>()
     }
+
+    /// Returns `component`'s default screenshot. Delegates to `Component::default_screenshot`.
+    pub fn default_screenshot<'a>(&self, component: &'a Component) -> Option<&'a Screenshot> {
+        component.default_screenshot()
+    }
+
+    /// Validates every component's screenshots, returning a warning for each issue found instead
+    /// of failing the parse.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        self.components
+            .iter()
+            .flat_map(|component| {
+                component
+                    .screenshots
+                    .iter()
+                    .enumerate()
+                    .flat_map(move |(index, screenshot)| screenshot.validate(&component.id, index))
+            })
+            .collect()
+    }
+
+    /// Joins every relative screenshot/video href onto `media_baseurl`; a no-op if unset.
+    pub fn resolve_media_urls(&mut self) {
+        let base = match &self.media_baseurl {
+            Some(base) => base.clone(),
+            None => return,
+        };
+
+        for component in &mut self.components {
+            for screenshot in &mut component.screenshots {
+                for image in &mut screenshot.images {
+                    image.url.resolve(&base);
+                }
+                for video in &mut screenshot.videos {
+                    video.url.resolve(&base);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
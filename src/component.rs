@@ -0,0 +1,12 @@
+use super::Screenshot;
+
+impl Component {
+    /// Returns this component's default screenshot (the one marked `is_default`, or the first
+    /// one if none is marked).
+    pub fn default_screenshot(&self) -> Option<&Screenshot> {
+        self.screenshots
+            .iter()
+            .find(|screenshot| screenshot.is_default)
+            .or_else(|| self.screenshots.first())
+    }
+}
@@ -0,0 +1,208 @@
+//! Downloads screenshot/video media referenced by a [`Component`] or [`Collection`] to a local
+//! directory, so software-center style UIs can cache it offline. Gated behind the `download`
+//! feature so the core parser doesn't pull in `reqwest` and friends.
+
+use super::enums::ImageKind;
+use super::{AppId, Collection, Component};
+use anyhow::Result;
+use futures_util::StreamExt;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use url::Url;
+
+/// Called after every chunk written to disk, with the bytes downloaded so far for the current
+/// file and the total size if the server reported a `Content-Length`.
+pub type ProgressCallback = Arc<dyn Fn(u64, Option<u64>) + Send + Sync>;
+
+/// Result of downloading a component's or collection's screenshot media.
+#[derive(Debug, Default)]
+pub struct DownloadResult {
+    /// Maps each downloaded media's original URL to the local path it was saved to.
+    pub downloaded: HashMap<Url, PathBuf>,
+    /// Number of images/videos skipped because their `MediaUrl` was still `MediaUrl::Relative`
+    /// (the caller likely forgot to call `Collection::resolve_media_urls` first).
+    pub skipped_unresolved: usize,
+}
+
+/// Downloads the screenshot images/videos of components to a local cache directory.
+pub struct MediaDownloader {
+    client: reqwest::Client,
+}
+
+impl Default for MediaDownloader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MediaDownloader {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Downloads all screenshot media of a single component into `target_dir`. Images/videos
+    /// whose `MediaUrl` is still `MediaUrl::Relative` (see `DownloadResult::skipped_unresolved`)
+    /// are skipped — call `Collection::resolve_media_urls` first if that's not what you want.
+    pub async fn download_component(
+        &self,
+        component: &Component,
+        target_dir: &Path,
+        progress: Option<ProgressCallback>,
+    ) -> Result<DownloadResult> {
+        tokio::fs::create_dir_all(target_dir).await?;
+
+        let mut result = DownloadResult::default();
+        for screenshot in &component.screenshots {
+            for image in &screenshot.images {
+                let url = match image.url.as_url() {
+                    Some(url) => url.clone(),
+                    None => {
+                        result.skipped_unresolved += 1;
+                        continue;
+                    }
+                };
+                let kind = match image.kind {
+                    ImageKind::Source => "source",
+                    ImageKind::Thumbnail => "thumbnail",
+                };
+                let filename = media_filename(&component.id, kind, image.width, image.height, &url);
+                let path = self
+                    .download_to(&url, &target_dir.join(filename), progress.clone())
+                    .await?;
+                result.downloaded.insert(url, path);
+            }
+
+            for video in &screenshot.videos {
+                let url = match video.url.as_url() {
+                    Some(url) => url.clone(),
+                    None => {
+                        result.skipped_unresolved += 1;
+                        continue;
+                    }
+                };
+                let filename = media_filename(&component.id, "video", video.width, video.height, &url);
+                let path = self
+                    .download_to(&url, &target_dir.join(filename), progress.clone())
+                    .await?;
+                result.downloaded.insert(url, path);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Downloads all screenshot media of every component in `collection` into `target_dir`. See
+    /// `download_component` for how unresolved media URLs are handled.
+    pub async fn download_collection(
+        &self,
+        collection: &Collection,
+        target_dir: &Path,
+        progress: Option<ProgressCallback>,
+    ) -> Result<DownloadResult> {
+        let mut result = DownloadResult::default();
+        for component in &collection.components {
+            let component_result = self
+                .download_component(component, target_dir, progress.clone())
+                .await?;
+            result.downloaded.extend(component_result.downloaded);
+            result.skipped_unresolved += component_result.skipped_unresolved;
+        }
+        Ok(result)
+    }
+
+    async fn download_to(
+        &self,
+        url: &Url,
+        path: &Path,
+        progress: Option<ProgressCallback>,
+    ) -> Result<PathBuf> {
+        let response = self.client.get(url.clone()).send().await?.error_for_status()?;
+        let total = response.content_length();
+
+        let mut file = File::create(path).await?;
+        let mut stream = response.bytes_stream();
+        let mut downloaded = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk).await?;
+            downloaded += chunk.len() as u64;
+            if let Some(progress) = &progress {
+                progress(downloaded, total);
+            }
+        }
+
+        Ok(path.to_path_buf())
+    }
+}
+
+/// Builds a filesystem-safe, deterministic filename for a piece of screenshot media, e.g.
+/// `org.mozilla.Firefox-source-800x600-5f0e3a9c1b7d2e48.png`. The trailing hash is derived from the
+/// source URL so that multiple screenshots/images sharing a kind and resolution (very common)
+/// don't collide and silently overwrite each other on disk.
+fn media_filename(app_id: &AppId, kind: &str, width: Option<u32>, height: Option<u32>, url: &Url) -> String {
+    let safe_id: String = app_id
+        .0
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+
+    let resolution = match (width, height) {
+        (Some(w), Some(h)) => format!("{w}x{h}"),
+        _ => "unknown".to_string(),
+    };
+
+    let extension = Path::new(url.path())
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("bin");
+
+    let mut hasher = DefaultHasher::new();
+    url.as_str().hash(&mut hasher);
+    let hash = hasher.finish();
+
+    format!("{safe_id}-{kind}-{resolution}-{hash:08x}.{extension}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn media_filename_disambiguates_same_kind_and_resolution() {
+        let app_id = AppId("org.mozilla.Firefox".into());
+        let a = media_filename(
+            &app_id,
+            "thumbnail",
+            Some(752),
+            Some(423),
+            &Url::parse("https://example.org/en_US/shot1.png").unwrap(),
+        );
+        let b = media_filename(
+            &app_id,
+            "thumbnail",
+            Some(752),
+            Some(423),
+            &Url::parse("https://example.org/en_US/shot2.png").unwrap(),
+        );
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn media_filename_is_deterministic() {
+        let app_id = AppId("org.mozilla.Firefox".into());
+        let url = Url::parse("https://example.org/en_US/shot1.png").unwrap();
+
+        assert_eq!(
+            media_filename(&app_id, "thumbnail", Some(752), Some(423), &url),
+            media_filename(&app_id, "thumbnail", Some(752), Some(423), &url)
+        );
+    }
+}
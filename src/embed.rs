@@ -0,0 +1,207 @@
+//! Turns remote screenshot media into `data:` URIs so a [`Collection`] can be shipped as a single
+//! self-contained offline artifact, mirroring what page-archiving tools do for HTML assets. Gated
+//! behind the `download` feature, since it has to fetch every embedded asset over HTTP.
+
+use super::enums::ImageKind;
+use super::screenshot::MediaUrl;
+use super::{Collection, Component, Screenshot};
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::header::CONTENT_TYPE;
+
+/// Controls which media gets embedded as `data:` URIs.
+#[derive(Clone, Debug)]
+pub struct EmbedOptions {
+    /// Only embed thumbnails, leaving `ImageKind::Source` images (typically much larger) as
+    /// remote links.
+    pub thumbnails_only: bool,
+    /// Whether to embed videos at all. Videos tend to be large, so callers may want to drop them
+    /// rather than inline them.
+    pub embed_video: bool,
+    /// Skip embedding videos whose `Content-Length` exceeds this many bytes, leaving them as
+    /// remote links instead. Checked before downloading the body. `None` disables the check.
+    /// Only takes effect when `embed_video` is set.
+    pub max_video_bytes: Option<u64>,
+}
+
+impl Default for EmbedOptions {
+    fn default() -> Self {
+        Self {
+            thumbnails_only: true,
+            embed_video: false,
+            max_video_bytes: Some(5 * 1024 * 1024),
+        }
+    }
+}
+
+/// Replaces every eligible screenshot media URL in `collection` with an embedded `data:` URI,
+/// returning a new, self-contained `Collection`.
+pub async fn embed_collection(collection: &Collection, options: &EmbedOptions) -> Result<Collection> {
+    let mut collection = collection.clone();
+    for component in &mut collection.components {
+        embed_component(component, options).await?;
+    }
+    Ok(collection)
+}
+
+/// Replaces every eligible screenshot media URL of a single `component` with an embedded `data:`
+/// URI, in place.
+pub async fn embed_component(component: &mut Component, options: &EmbedOptions) -> Result<()> {
+    for screenshot in &mut component.screenshots {
+        embed_screenshot(screenshot, options).await?;
+    }
+    Ok(())
+}
+
+/// Replaces every eligible media URL of a single `screenshot` with an embedded `data:` URI, in
+/// place.
+pub async fn embed_screenshot(screenshot: &mut Screenshot, options: &EmbedOptions) -> Result<()> {
+    for image in &mut screenshot.images {
+        if !should_embed_image(options, image.kind) {
+            continue;
+        }
+        if let Some(url) = image.url.as_url() {
+            let data_uri = fetch_as_data_uri(url.as_str()).await?;
+            image.url = MediaUrl::Absolute(data_uri);
+        }
+    }
+
+    if options.embed_video {
+        for video in &mut screenshot.videos {
+            if let Some(url) = video.url.as_url() {
+                if let Some(data_uri) =
+                    fetch_video_as_data_uri(url.as_str(), options.max_video_bytes).await?
+                {
+                    video.url = MediaUrl::Absolute(data_uri);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether an image of `kind` is eligible for embedding under `options`.
+fn should_embed_image(options: &EmbedOptions, kind: ImageKind) -> bool {
+    !(options.thumbnails_only && kind == ImageKind::Source)
+}
+
+/// Whether a video reporting `content_length` bytes is too large to embed under `max_bytes`.
+fn exceeds_video_size_limit(max_bytes: Option<u64>, content_length: Option<u64>) -> bool {
+    matches!((max_bytes, content_length), (Some(max), Some(len)) if len > max)
+}
+
+/// Picks the MIME type to embed a `data:` URI with: the response's `Content-Type` if present,
+/// falling back to a guess from the URL's file extension.
+fn resolve_mime(content_type: Option<&str>, url: &str) -> String {
+    content_type.map(|value| value.to_string()).unwrap_or_else(|| {
+        mime_guess::from_path(url)
+            .first_or_octet_stream()
+            .to_string()
+    })
+}
+
+/// Base64-encodes `bytes` into a `data:<mime>;base64,<payload>` URI.
+fn build_data_uri(mime: &str, bytes: &[u8]) -> Result<url::Url> {
+    let payload = STANDARD.encode(bytes);
+    Ok(url::Url::parse(&format!("data:{mime};base64,{payload}"))?)
+}
+
+async fn fetch_as_data_uri(url: &str) -> Result<url::Url> {
+    let response = reqwest::get(url).await?.error_for_status()?;
+
+    let mime = resolve_mime(
+        response.headers().get(CONTENT_TYPE).and_then(|value| value.to_str().ok()),
+        url,
+    );
+    let bytes = response.bytes().await?;
+
+    build_data_uri(&mime, &bytes)
+}
+
+/// Like [`fetch_as_data_uri`], but returns `Ok(None)` instead of downloading the body when the
+/// response's `Content-Length` exceeds `max_bytes`.
+async fn fetch_video_as_data_uri(url: &str, max_bytes: Option<u64>) -> Result<Option<url::Url>> {
+    let response = reqwest::get(url).await?.error_for_status()?;
+
+    if exceeds_video_size_limit(max_bytes, response.content_length()) {
+        return Ok(None);
+    }
+
+    let mime = resolve_mime(
+        response.headers().get(CONTENT_TYPE).and_then(|value| value.to_str().ok()),
+        url,
+    );
+    let bytes = response.bytes().await?;
+
+    Ok(Some(build_data_uri(&mime, &bytes)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbnails_only_skips_source_images() {
+        let options = EmbedOptions {
+            thumbnails_only: true,
+            ..EmbedOptions::default()
+        };
+
+        assert!(!should_embed_image(&options, ImageKind::Source));
+        assert!(should_embed_image(&options, ImageKind::Thumbnail));
+    }
+
+    #[test]
+    fn thumbnails_only_disabled_embeds_everything() {
+        let options = EmbedOptions {
+            thumbnails_only: false,
+            ..EmbedOptions::default()
+        };
+
+        assert!(should_embed_image(&options, ImageKind::Source));
+        assert!(should_embed_image(&options, ImageKind::Thumbnail));
+    }
+
+    #[test]
+    fn video_over_limit_is_skipped() {
+        assert!(exceeds_video_size_limit(Some(1_000), Some(1_001)));
+    }
+
+    #[test]
+    fn video_within_limit_is_not_skipped() {
+        assert!(!exceeds_video_size_limit(Some(1_000), Some(1_000)));
+    }
+
+    #[test]
+    fn no_limit_never_skips() {
+        assert!(!exceeds_video_size_limit(None, Some(u64::MAX)));
+    }
+
+    #[test]
+    fn unknown_content_length_never_skips() {
+        assert!(!exceeds_video_size_limit(Some(1_000), None));
+    }
+
+    #[test]
+    fn resolve_mime_prefers_content_type() {
+        assert_eq!(
+            resolve_mime(Some("image/png"), "https://example.org/shot.webm"),
+            "image/png"
+        );
+    }
+
+    #[test]
+    fn resolve_mime_falls_back_to_url_extension() {
+        assert_eq!(
+            resolve_mime(None, "https://example.org/shot.webm"),
+            "video/webm"
+        );
+    }
+
+    #[test]
+    fn build_data_uri_formats_base64_payload() {
+        let uri = build_data_uri("image/png", b"hello").unwrap();
+        assert_eq!(uri.as_str(), "data:image/png;base64,aGVsbG8=");
+    }
+}
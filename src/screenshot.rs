@@ -1,9 +1,38 @@
 use super::de::*;
 use super::enums::ImageKind;
 use super::types::TranslatableString;
+use super::AppId;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+/// The `href` of an `<image>` or `<video>` element, possibly still relative to `media_baseurl`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum MediaUrl {
+    Absolute(Url),
+    Relative(String),
+}
+
+impl MediaUrl {
+    /// Returns the URL, if it is already absolute.
+    pub fn as_url(&self) -> Option<&Url> {
+        match self {
+            MediaUrl::Absolute(url) => Some(url),
+            MediaUrl::Relative(_) => None,
+        }
+    }
+
+    /// Joins a relative href onto `base`, turning it into an absolute URL. Does nothing if the
+    /// href is already absolute, or if joining fails.
+    pub fn resolve(&mut self, base: &Url) {
+        if let MediaUrl::Relative(path) = self {
+            if let Ok(joined) = base.join(path) {
+                *self = MediaUrl::Absolute(joined);
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Screenshot {
     #[serde(
@@ -31,6 +60,70 @@ impl Default for Screenshot {
     }
 }
 
+impl Screenshot {
+    /// Returns this screenshot's full-resolution `ImageKind::Source` image, if any.
+    pub fn source_image(&self) -> Option<&Image> {
+        self.images.iter().find(|image| image.kind == ImageKind::Source)
+    }
+
+    /// Returns the thumbnail whose width is the smallest one `>= target_width`, falling back to
+    /// the largest available thumbnail when none are big enough, and to the source image when
+    /// there are no thumbnails with a known width. Thumbnails without a `width` are never picked.
+    pub fn best_thumbnail(&self, target_width: u32) -> Option<&Image> {
+        let mut thumbnails: Vec<&Image> = self
+            .images
+            .iter()
+            .filter(|image| image.kind == ImageKind::Thumbnail && image.width.is_some())
+            .collect();
+        thumbnails.sort_by_key(|image| image.width.unwrap());
+
+        thumbnails
+            .iter()
+            .find(|image| image.width.unwrap() >= target_width)
+            .or_else(|| thumbnails.last())
+            .copied()
+            .or_else(|| self.source_image())
+    }
+
+    /// Validates this screenshot, tagging each warning with `component` and this screenshot's
+    /// `index` in its component so callers auditing a whole collection know what to fix.
+    pub fn validate(&self, component: &AppId, index: usize) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        if self.images.is_empty() && self.videos.is_empty() {
+            warnings.push(ValidationWarning::ScreenshotMissingMedia {
+                component: component.clone(),
+                screenshot_index: index,
+            });
+        }
+
+        for video in &self.videos {
+            if video.width.is_none() || video.height.is_none() {
+                warnings.push(ValidationWarning::VideoMissingDimensions {
+                    component: component.clone(),
+                    screenshot_index: index,
+                });
+            }
+            if let Some(VideoCodec::Unknown(codec)) = &video.codec {
+                warnings.push(ValidationWarning::DisallowedVideoCodec {
+                    component: component.clone(),
+                    screenshot_index: index,
+                    codec: codec.clone(),
+                });
+            }
+            if let Some(VideoContainer::Unknown(container)) = &video.container {
+                warnings.push(ValidationWarning::DisallowedVideoContainer {
+                    component: component.clone(),
+                    screenshot_index: index,
+                    container: container.clone(),
+                });
+            }
+        }
+
+        warnings
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Video {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -38,11 +131,112 @@ pub struct Video {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub height: Option<u32>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub codec: Option<String>,
+    pub codec: Option<VideoCodec>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub container: Option<String>,
+    pub container: Option<VideoContainer>,
     #[serde(rename = "$value")]
-    pub url: Url,
+    pub url: MediaUrl,
+}
+
+/// Video codec, restricted by the AppStream spec to `av1` and `vp9`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VideoCodec {
+    AV1,
+    VP9,
+    /// A codec value the spec doesn't (yet) allow, kept around so unusual metadata still
+    /// round-trips instead of failing to parse.
+    Unknown(String),
+}
+
+impl Serialize for VideoCodec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            VideoCodec::AV1 => "av1",
+            VideoCodec::VP9 => "vp9",
+            VideoCodec::Unknown(value) => value,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for VideoCodec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "av1" => VideoCodec::AV1,
+            "vp9" => VideoCodec::VP9,
+            _ => VideoCodec::Unknown(value),
+        })
+    }
+}
+
+/// Video container format, restricted by the AppStream spec to `webm` and `matroska`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VideoContainer {
+    WebM,
+    Matroska,
+    /// A container value the spec doesn't (yet) allow, kept around so unusual metadata still
+    /// round-trips instead of failing to parse.
+    Unknown(String),
+}
+
+impl Serialize for VideoContainer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            VideoContainer::WebM => "webm",
+            VideoContainer::Matroska => "matroska",
+            VideoContainer::Unknown(value) => value,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for VideoContainer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "webm" => VideoContainer::WebM,
+            "matroska" => VideoContainer::Matroska,
+            _ => VideoContainer::Unknown(value),
+        })
+    }
+}
+
+/// A single issue found while validating a [`Screenshot`] or [`Collection`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationWarning {
+    /// A video uses a codec the spec doesn't allow.
+    DisallowedVideoCodec {
+        component: AppId,
+        screenshot_index: usize,
+        codec: String,
+    },
+    /// A video uses a container format the spec doesn't allow.
+    DisallowedVideoContainer {
+        component: AppId,
+        screenshot_index: usize,
+        container: String,
+    },
+    /// A video is missing its `width`/`height` attributes.
+    VideoMissingDimensions {
+        component: AppId,
+        screenshot_index: usize,
+    },
+    /// A screenshot has neither images nor videos.
+    ScreenshotMissingMedia {
+        component: AppId,
+        screenshot_index: usize,
+    },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -54,7 +248,7 @@ pub struct Image {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub height: Option<u32>,
     #[serde(rename = "$value")]
-    pub url: Url,
+    pub url: MediaUrl,
 }
 
 #[cfg(test)]
@@ -125,6 +319,31 @@ mod tests {
         assert_eq!(s1, s2);
     }
 
+    #[test]
+    fn relative_image_url_resolved_against_media_baseurl() {
+        let xml = r"
+            <screenshot type='default'>
+                <image type='source' width='800' height='600'>firefox/main.png</image>
+            </screenshot>";
+        let mut s: Screenshot = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(s.images[0].url, MediaUrl::Relative("firefox/main.png".into()));
+
+        let base = Url::parse("https://www.example.org/data/en/").unwrap();
+        s.images[0].url.resolve(&base);
+        assert_eq!(
+            s.images[0].url,
+            MediaUrl::Absolute(Url::parse("https://www.example.org/data/en/firefox/main.png").unwrap())
+        );
+    }
+
+    #[test]
+    fn absolute_image_url_untouched_by_resolve() {
+        let url = Url::parse("https://www.example.org/en_US/main.png").unwrap();
+        let mut media = MediaUrl::Absolute(url.clone());
+        media.resolve(&Url::parse("https://other.example.org/").unwrap());
+        assert_eq!(media, MediaUrl::Absolute(url));
+    }
+
     #[test]
     fn screenshot_video() {
         let xml = r"
@@ -145,4 +364,95 @@ mod tests {
             .build();
         assert_eq!(s1, s2);
     }
+
+    #[test]
+    fn unknown_video_codec_and_container_round_trip() {
+        let xml = r"
+            <screenshot>
+                <video codec='theora' container='ogg' width='1600' height='900'>https://example.com/screencast.ogv</video>
+            </screenshot>";
+        let s: Screenshot = quick_xml::de::from_str(&xml).unwrap();
+
+        assert_eq!(s.videos[0].codec, Some(VideoCodec::Unknown("theora".into())));
+        assert_eq!(
+            s.videos[0].container,
+            Some(VideoContainer::Unknown("ogg".into()))
+        );
+    }
+
+    #[test]
+    fn validate_tags_warnings_with_component_and_index() {
+        use std::convert::TryFrom;
+
+        let xml = r"
+            <screenshot>
+                <video codec='theora' width='1600'>https://example.com/screencast.ogv</video>
+            </screenshot>";
+        let s: Screenshot = quick_xml::de::from_str(&xml).unwrap();
+        let app_id = AppId::try_from("org.example.Foo").unwrap();
+
+        let warnings = s.validate(&app_id, 2);
+        assert!(warnings.contains(&ValidationWarning::VideoMissingDimensions {
+            component: app_id.clone(),
+            screenshot_index: 2,
+        }));
+        assert!(warnings.contains(&ValidationWarning::DisallowedVideoCodec {
+            component: app_id,
+            screenshot_index: 2,
+            codec: "theora".into(),
+        }));
+    }
+
+    #[test]
+    fn best_thumbnail_picks_smallest_fit() {
+        let s = ScreenshotBuilder::new()
+            .image(
+                ImageBuilder::new(Url::parse("https://example.org/512.png").unwrap())
+                    .kind(ImageKind::Thumbnail)
+                    .width(512)
+                    .build(),
+            )
+            .image(
+                ImageBuilder::new(Url::parse("https://example.org/256.png").unwrap())
+                    .kind(ImageKind::Thumbnail)
+                    .width(256)
+                    .build(),
+            )
+            .image(
+                ImageBuilder::new(Url::parse("https://example.org/128.png").unwrap())
+                    .kind(ImageKind::Thumbnail)
+                    .width(128)
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(s.best_thumbnail(200).unwrap().width, Some(256));
+    }
+
+    #[test]
+    fn best_thumbnail_falls_back_to_largest_when_none_big_enough() {
+        let s = ScreenshotBuilder::new()
+            .image(
+                ImageBuilder::new(Url::parse("https://example.org/128.png").unwrap())
+                    .kind(ImageKind::Thumbnail)
+                    .width(128)
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(s.best_thumbnail(1000).unwrap().width, Some(128));
+    }
+
+    #[test]
+    fn best_thumbnail_falls_back_to_source_without_thumbnails() {
+        let s = ScreenshotBuilder::new()
+            .image(
+                ImageBuilder::new(Url::parse("https://example.org/main.png").unwrap())
+                    .width(800)
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(s.best_thumbnail(320).unwrap().kind, ImageKind::Source);
+    }
 }